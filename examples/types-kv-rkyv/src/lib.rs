@@ -2,8 +2,11 @@
 
 use std::fmt;
 
+use openraft::network::RPCTypes;
+
 /// A request to the KV store.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 pub enum Request {
     Set { key: String, value: String },
@@ -28,6 +31,7 @@ impl fmt::Display for Request {
 
 /// A response from the KV store.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 pub struct Response {
     pub value: Option<String>,
@@ -51,22 +55,245 @@ openraft::declare_raft_types!(
         R = Response,
 );
 
-pub fn encode_request(req: &Request) -> Result<rkyv::util::AlignedVec, rkyv::rancor::Error> {
-    rkyv::to_bytes::<rkyv::rancor::Error>(req)
+// The request/response wire path is generic over [`RaftCodec`]: the `*_with` functions take the
+// codec as a type parameter (this is where an example network client/server plugs in its chosen
+// format), and the bare functions keep the historical rkyv behavior by defaulting to [`RkyvCodec`].
+
+pub fn encode_request_with<C: RaftCodec<Request>>(req: &Request) -> Result<Vec<u8>, C::Error> {
+    C::encode(req)
+}
+
+pub fn decode_request_with<C: RaftCodec<Request>>(bytes: &[u8]) -> Result<Request, C::Error> {
+    C::decode(bytes)
+}
+
+pub fn encode_response_with<C: RaftCodec<Response>>(resp: &Response) -> Result<Vec<u8>, C::Error> {
+    C::encode(resp)
+}
+
+pub fn decode_response_with<C: RaftCodec<Response>>(bytes: &[u8]) -> Result<Response, C::Error> {
+    C::decode(bytes)
+}
+
+pub fn encode_request(req: &Request) -> Result<Vec<u8>, rkyv::rancor::Error> {
+    encode_request_with::<RkyvCodec>(req)
 }
 
 pub fn decode_request(bytes: &[u8]) -> Result<Request, rkyv::rancor::Error> {
-    rkyv::from_bytes::<Request, rkyv::rancor::Error>(bytes)
+    decode_request_with::<RkyvCodec>(bytes)
 }
 
-pub fn encode_response(resp: &Response) -> Result<rkyv::util::AlignedVec, rkyv::rancor::Error> {
-    rkyv::to_bytes::<rkyv::rancor::Error>(resp)
+pub fn encode_response(resp: &Response) -> Result<Vec<u8>, rkyv::rancor::Error> {
+    encode_response_with::<RkyvCodec>(resp)
 }
 
 pub fn decode_response(bytes: &[u8]) -> Result<Response, rkyv::rancor::Error> {
-    rkyv::from_bytes::<Response, rkyv::rancor::Error>(bytes)
+    decode_response_with::<RkyvCodec>(bytes)
+}
+
+/// A pluggable wire codec for the example network layer.
+///
+/// The trait is parameterized by the value type `T` rather than taking a generic method, so each
+/// codec can carry the bounds its format requires (`rkyv` serialization bounds, serde's
+/// `Serialize`/`DeserializeOwned`, ...). A network client/server generic over
+/// `C: RaftCodec<Request> + RaftCodec<Response>` can then swap the wire format — compact binary
+/// [`RkyvCodec`], self-describing [`JsonCodec`], or compact self-describing [`CborCodec`] — without
+/// touching the Raft core or the `AppData`/`AppDataResponse` types.
+pub trait RaftCodec<T> {
+    /// The error produced when encoding or decoding fails.
+    type Error: std::error::Error;
+
+    /// Encode a value to its wire bytes.
+    fn encode(value: &T) -> Result<Vec<u8>, Self::Error>;
+
+    /// Decode a value from its wire bytes.
+    fn decode(bytes: &[u8]) -> Result<T, Self::Error>;
+}
+
+/// Compact binary codec backed by `rkyv` — the format the example uses by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RkyvCodec;
+
+impl<T> RaftCodec<T> for RkyvCodec
+where
+    T: for<'a> rkyv::Serialize<
+            rkyv::api::high::HighSerializer<rkyv::util::AlignedVec, rkyv::ser::allocator::ArenaHandle<'a>, rkyv::rancor::Error>,
+        >,
+    T::Archived: rkyv::Deserialize<T, rkyv::api::high::HighDeserializer<rkyv::rancor::Error>>
+        + for<'a> rkyv::bytecheck::CheckBytes<rkyv::api::high::HighValidator<'a, rkyv::rancor::Error>>,
+{
+    type Error = rkyv::rancor::Error;
+
+    fn encode(value: &T) -> Result<Vec<u8>, Self::Error> {
+        rkyv::to_bytes::<rkyv::rancor::Error>(value).map(|b| b.to_vec())
+    }
+
+    fn decode(bytes: &[u8]) -> Result<T, Self::Error> {
+        // `encode` returns a plain `Vec<u8>` whose buffer is only byte-aligned, but rkyv's validated
+        // access requires the archived root to meet its alignment. Copy into an `AlignedVec` first so
+        // arbitrary byte slices off the wire decode correctly.
+        let mut aligned = rkyv::util::AlignedVec::<16>::new();
+        aligned.extend_from_slice(bytes);
+        rkyv::from_bytes::<T, rkyv::rancor::Error>(&aligned)
+    }
+}
+
+/// Self-describing JSON codec backed by `serde_json`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl<T> RaftCodec<T> for JsonCodec
+where T: serde::Serialize + serde::de::DeserializeOwned
+{
+    type Error = serde_json::Error;
+
+    fn encode(value: &T) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(value)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<T, Self::Error> {
+        serde_json::from_slice(bytes)
+    }
+}
+
+/// Compact self-describing CBOR codec backed by `ciborium`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CborCodec;
+
+impl<T> RaftCodec<T> for CborCodec
+where T: serde::Serialize + serde::de::DeserializeOwned
+{
+    type Error = CborError;
+
+    fn encode(value: &T) -> Result<Vec<u8>, Self::Error> {
+        let mut buf = Vec::new();
+        ciborium::into_writer(value, &mut buf).map_err(CborError::Encode)?;
+        Ok(buf)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<T, Self::Error> {
+        ciborium::from_reader(bytes).map_err(CborError::Decode)
+    }
+}
+
+/// Error returned by [`CborCodec`], unifying `ciborium`'s separate encode and decode errors.
+#[derive(Debug)]
+pub enum CborError {
+    Encode(ciborium::ser::Error<std::io::Error>),
+    Decode(ciborium::de::Error<std::io::Error>),
+}
+
+impl fmt::Display for CborError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CborError::Encode(e) => write!(f, "cbor encode error: {}", e),
+            CborError::Decode(e) => write!(f, "cbor decode error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CborError {}
+
+/// A framed Raft RPC: a cheap-to-inspect header followed by the still-encoded payload.
+///
+/// The header (`rpc_type` and `term`) lets a server classify and route an incoming frame — and
+/// reject stale terms — without allocating or deserializing the potentially large `payload` (an
+/// entry batch or a snapshot chunk). The payload stays as opaque bytes until the server decides the
+/// frame is worth decoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
+pub struct RpcFrame {
+    pub rpc_type: RPCTypes,
+    pub term: u64,
+    pub payload: Vec<u8>,
+}
+
+impl RpcFrame {
+    pub fn new(rpc_type: RPCTypes, term: u64, payload: impl Into<Vec<u8>>) -> Self {
+        RpcFrame {
+            rpc_type,
+            term,
+            payload: payload.into(),
+        }
+    }
+}
+
+/// The decoded header of an [`RpcFrame`], read without touching the payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcHeader {
+    pub rpc_type: RPCTypes,
+    pub term: u64,
+}
+
+pub fn encode_rpc(frame: &RpcFrame) -> Result<rkyv::util::AlignedVec, rkyv::rancor::Error> {
+    rkyv::to_bytes::<rkyv::rancor::Error>(frame)
+}
+
+/// Obtain a validated, borrowed view into an archived [`RpcFrame`] without copying.
+///
+/// `rkyv::access` runs `CheckBytes` validation, so malformed or adversarial buffers are rejected
+/// rather than producing out-of-bounds reads; the returned reference borrows from `bytes`.
+///
+/// # Alignment
+///
+/// Because this is zero-copy, `bytes` must be aligned to rkyv's root alignment (16 bytes) — the
+/// alignment [`encode_rpc`] already produces via [`AlignedVec`](rkyv::util::AlignedVec). Bytes
+/// sliced straight out of a socket or framing buffer are routinely unaligned, and such input is
+/// rejected with an `UnalignedPointer` error. A network layer that cannot guarantee alignment must
+/// realign first (e.g. copy into an `AlignedVec`, as [`RkyvCodec::decode`] does) before peeking.
+pub fn access_rpc(bytes: &[u8]) -> Result<&ArchivedRpcFrame, rkyv::rancor::Error> {
+    let archived = rkyv::access::<ArchivedRpcFrame, rkyv::rancor::Error>(bytes)?;
+
+    // Defense in depth: `CheckBytes` already bounds-checks every relative pointer, but confirm the
+    // archived `payload` slice lies entirely inside the input frame before handing back a borrow.
+    if !payload_in_bounds(bytes, &archived.payload) {
+        return Err(<rkyv::rancor::Error as rkyv::rancor::Source>::new(OutOfBounds));
+    }
+
+    Ok(archived)
 }
 
+/// Peek the header of an encoded [`RpcFrame`] for dispatch, leaving the payload untouched.
+///
+/// Same 16-byte alignment precondition as [`access_rpc`]: unaligned wire bytes must be realigned
+/// before calling.
+pub fn peek_header(bytes: &[u8]) -> Result<RpcHeader, rkyv::rancor::Error> {
+    let archived = access_rpc(bytes)?;
+
+    // Only the small header is deserialized; the payload bytes are never decoded here.
+    let rpc_type = rkyv::deserialize::<RPCTypes, rkyv::rancor::Error>(&archived.rpc_type)?;
+
+    Ok(RpcHeader {
+        rpc_type,
+        term: archived.term.to_native(),
+    })
+}
+
+/// Check that the archived `payload` bytes fall inside `bytes`' address range.
+fn payload_in_bounds(bytes: &[u8], payload: &rkyv::vec::ArchivedVec<u8>) -> bool {
+    let base = bytes.as_ptr() as usize;
+    let Some(end) = base.checked_add(bytes.len()) else {
+        return false;
+    };
+
+    let start = payload.as_ptr() as usize;
+    match start.checked_add(payload.len()) {
+        Some(stop) => start >= base && stop <= end,
+        None => false,
+    }
+}
+
+#[derive(Debug)]
+struct OutOfBounds;
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "archived RpcFrame payload points outside the input buffer")
+    }
+}
+
+impl std::error::Error for OutOfBounds {}
+
 #[cfg(test)]
 mod tests {
     use openraft::raft::VoteRequest;
@@ -107,4 +334,63 @@ mod tests {
         assert!(!archive.vote.committed);
         assert!(archive.last_log_id.is_none());
     }
+
+    #[test]
+    fn test_peek_header_without_decoding_payload() {
+        let payload = encode_request(&Request::set("k1", "v1")).unwrap();
+        let frame = RpcFrame::new(RPCTypes::AppendEntries, 7, payload.as_slice());
+
+        let bytes = encode_rpc(&frame).unwrap();
+
+        let header = peek_header(&bytes).unwrap();
+        assert_eq!(header.rpc_type, RPCTypes::AppendEntries);
+        assert_eq!(header.term, 7);
+
+        // The borrowed view exposes the payload without copying it.
+        let archived = access_rpc(&bytes).unwrap();
+        assert_eq!(archived.payload.as_slice(), payload.as_slice());
+    }
+
+    #[test]
+    fn test_access_rpc_rejects_garbage() {
+        let garbage = [0xffu8; 8];
+        assert!(access_rpc(&garbage).is_err());
+    }
+
+    #[test]
+    fn test_access_rpc_requires_alignment() {
+        let payload = encode_request(&Request::set("k1", "v1")).unwrap();
+        let frame = RpcFrame::new(RPCTypes::Vote, 3, payload.as_slice());
+        let bytes = encode_rpc(&frame).unwrap();
+
+        // Shift the valid frame by one byte to mimic bytes sliced out of an unaligned wire buffer:
+        // the zero-copy peek rejects it, and the caller must realign (copy) before retrying. Backing
+        // the buffer with an `AlignedVec` makes its base 16-aligned, so `[1..]` is reliably off.
+        let mut backing = rkyv::util::AlignedVec::<16>::new();
+        backing.extend_from_slice(&[0u8]);
+        backing.extend_from_slice(bytes.as_ref());
+        assert!(access_rpc(&backing[1..]).is_err());
+
+        let mut realigned = rkyv::util::AlignedVec::<16>::new();
+        realigned.extend_from_slice(&backing[1..]);
+        assert_eq!(peek_header(&realigned).unwrap().term, 3);
+    }
+
+    fn assert_codec_roundtrip<C>()
+    where C: RaftCodec<Request> + RaftCodec<Response> {
+        let req = Request::set("k1", "v1");
+        let bytes = <C as RaftCodec<Request>>::encode(&req).unwrap();
+        assert_eq!(req, <C as RaftCodec<Request>>::decode(&bytes).unwrap());
+
+        let resp = Response::new("v1");
+        let bytes = <C as RaftCodec<Response>>::encode(&resp).unwrap();
+        assert_eq!(resp, <C as RaftCodec<Response>>::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_codec_roundtrip() {
+        assert_codec_roundtrip::<RkyvCodec>();
+        assert_codec_roundtrip::<JsonCodec>();
+        assert_codec_roundtrip::<CborCodec>();
+    }
 }
@@ -15,12 +15,50 @@ use crate::errors::ErrorSource;
 ///
 /// Use [`AnyError`] directly if you prefer inline storage and don't mind
 /// larger error types.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize))]
 pub struct BoxedErrorSource {
     #[cfg_attr(feature = "rkyv", rkyv(with = AnyErrorAsString))]
     inner: Box<AnyError>,
+
+    /// The backtrace captured when this error was wrapped, rendered to frames.
+    ///
+    /// Captured at construction (when `RUST_BACKTRACE` requests it) so the origin of a fault
+    /// survives being logged, and serialized as a plain string through the same serde/rkyv paths as
+    /// `inner`. On the serde path `#[serde(default)]` keeps state written by an older node (without
+    /// this field) decodable. Note the rkyv path has no optional-field support: appending this field
+    /// changes `ArchivedBoxedErrorSource`'s layout, so an archive produced by an older node cannot be
+    /// decoded by a newer one (and vice versa) — rkyv cross-version compatibility is not provided.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    backtrace: Option<String>,
+}
+
+// The backtrace is diagnostic-only and differs per construction site, so two errors that wrap the
+// same `AnyError` must still compare equal; exclude it from equality.
+impl PartialEq for BoxedErrorSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for BoxedErrorSource {}
+
+/// Capture the current backtrace as rendered frames, if enabled.
+///
+/// Returns `None` unless `RUST_BACKTRACE` (or `RUST_LIB_BACKTRACE`) requests capture;
+/// [`std::backtrace::Backtrace::capture`] honors those environment variables for us, so no cargo
+/// feature is involved.
+fn capture_backtrace() -> Option<String> {
+    use std::backtrace::Backtrace;
+    use std::backtrace::BacktraceStatus;
+
+    let bt = Backtrace::capture();
+    if bt.status() == BacktraceStatus::Captured {
+        Some(bt.to_string())
+    } else {
+        None
+    }
 }
 
 #[cfg(feature = "rkyv")]
@@ -79,21 +117,23 @@ impl ErrorSource for BoxedErrorSource {
     fn from_error<E: Error + 'static>(error: &E) -> Self {
         Self {
             inner: Box::new(AnyError::new(error)),
+            backtrace: capture_backtrace(),
         }
     }
 
     fn from_string(msg: impl ToString) -> Self {
         Self {
             inner: Box::new(AnyError::error(msg)),
+            backtrace: capture_backtrace(),
         }
     }
 
     fn has_backtrace(&self) -> bool {
-        anyerror::backtrace_str().is_some()
+        self.backtrace.is_some()
     }
 
     fn fmt_backtrace(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(bt) = anyerror::backtrace_str() {
+        if let Some(bt) = &self.backtrace {
             write!(f, "{}", bt)
         } else {
             Ok(())
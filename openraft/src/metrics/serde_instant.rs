@@ -1,28 +1,74 @@
 use std::fmt;
 use std::fmt::Formatter;
+use std::marker::PhantomData;
 use std::ops::Deref;
 
 use crate::Instant;
 use crate::display_ext::DisplayInstantExt;
 
+/// The epoch precision used when a [`SerdeInstant`] is serialized to a compact integer.
+///
+/// Coarser precision yields smaller wire encodings at the cost of a larger reconstruction error
+/// bound (see [`Nanos`], [`Millis`] and [`Seconds`]).
+pub trait Precision {
+    /// The number of nanoseconds represented by one unit of this precision.
+    const NANOS_PER_UNIT: u64;
+}
+
+/// Nanosecond precision; the backward-compatible default for [`SerdeInstant`].
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+#[derive(PartialOrd, Ord)]
+pub struct Nanos;
+
+/// Millisecond precision; reconstruction error is bounded by ~1ms.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+#[derive(PartialOrd, Ord)]
+pub struct Millis;
+
+/// Second precision; reconstruction error is bounded by ~1s.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+#[derive(PartialOrd, Ord)]
+pub struct Seconds;
+
+impl Precision for Nanos {
+    const NANOS_PER_UNIT: u64 = 1;
+}
+
+impl Precision for Millis {
+    const NANOS_PER_UNIT: u64 = 1_000_000;
+}
+
+impl Precision for Seconds {
+    const NANOS_PER_UNIT: u64 = 1_000_000_000;
+}
+
 /// A wrapper for [`Instant`] that supports serialization and deserialization.
 ///
 /// This struct serializes an `Instant` into an `i64` which is the number of non-leap-nanoseconds
 /// since January 1, 1970 UTC.
 ///
+/// In human-readable formats (JSON, YAML, ...) it is emitted as an RFC3339 string; in compact or
+/// binary formats (bincode, rkyv, ...) it is emitted as an integer count of `P`-sized units since
+/// the epoch. The precision `P` defaults to [`Nanos`], which preserves the original wire format;
+/// [`Millis`] or [`Seconds`] trade accuracy for a smaller encoding and wider interop.
+///
 /// Note: Serialization and deserialization are not perfectly accurate and can be indeterministic,
-/// resulting in minor variations each time. These deviations (could be smaller or greater) are
-/// typically less than a microsecond (10^-6 seconds).
+/// resulting in minor variations each time. With [`Nanos`] these deviations are typically less than
+/// a microsecond (10^-6 seconds); coarser precision widens the bound to one unit of `P`.
 #[derive(Debug, Clone, Copy)]
 #[derive(PartialEq, Eq)]
 #[derive(PartialOrd, Ord)]
-pub struct SerdeInstant<I>
+pub struct SerdeInstant<I, P = Nanos>
 where I: Instant
 {
     inner: I,
+    precision: PhantomData<P>,
 }
 
-impl<I> Deref for SerdeInstant<I>
+impl<I, P> Deref for SerdeInstant<I, P>
 where I: Instant
 {
     type Target = I;
@@ -32,15 +78,18 @@ where I: Instant
     }
 }
 
-impl<I> From<I> for SerdeInstant<I>
+impl<I, P> From<I> for SerdeInstant<I, P>
 where I: Instant
 {
     fn from(inner: I) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            precision: PhantomData,
+        }
     }
 }
 
-impl<I> fmt::Display for SerdeInstant<I>
+impl<I, P> fmt::Display for SerdeInstant<I, P>
 where I: Instant
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -48,12 +97,15 @@ where I: Instant
     }
 }
 
-impl<I> SerdeInstant<I>
+impl<I, P> SerdeInstant<I, P>
 where I: Instant
 {
     /// Create a new SerdeInstant wrapping the given Instant.
     pub fn new(inner: I) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            precision: PhantomData,
+        }
     }
 
     /// Extract the inner Instant value.
@@ -69,6 +121,7 @@ mod serde_impl {
     use std::time::SystemTime;
 
     use chrono::DateTime;
+    use chrono::SecondsFormat;
     use chrono::Utc;
     use serde::Deserialize;
     use serde::Deserializer;
@@ -77,11 +130,14 @@ mod serde_impl {
     use serde::de;
     use serde::de::Visitor;
 
+    use super::Precision;
     use super::SerdeInstant;
     use crate::Instant;
 
-    impl<I> Serialize for SerdeInstant<I>
-    where I: Instant
+    impl<I, P> Serialize for SerdeInstant<I, P>
+    where
+        I: Instant,
+        P: Precision,
     {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where S: Serializer {
@@ -101,45 +157,87 @@ mod serde_impl {
 
             let datetime: DateTime<Utc> = system_time.into();
 
-            let nano = datetime.timestamp_nanos_opt().ok_or(serde::ser::Error::custom("time out of range"))?;
+            // Human-readable formats (JSON, YAML, ...) get an RFC3339 string so that debug dumps and
+            // snapshots are legible; compact/binary formats (bincode, rkyv, ...) keep a wire-compact
+            // integer epoch count in units of `P`.
+            if serializer.is_human_readable() {
+                serializer.serialize_str(&datetime.to_rfc3339_opts(SecondsFormat::Nanos, true))
+            } else {
+                let nano = datetime.timestamp_nanos_opt().ok_or(serde::ser::Error::custom("time out of range"))?;
 
-            serializer.serialize_u64(nano as u64)
+                // Emit the count of `P`-sized units; `Nanos` keeps the original nanosecond integer.
+                serializer.serialize_u64(nano as u64 / P::NANOS_PER_UNIT)
+            }
         }
     }
 
-    impl<'de, I> Deserialize<'de> for SerdeInstant<I>
-    where I: Instant
+    impl<'de, I, P> Deserialize<'de> for SerdeInstant<I, P>
+    where
+        I: Instant,
+        P: Precision,
     {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: Deserializer<'de> {
-            struct InstantVisitor<II: Instant>(PhantomData<II>);
+            struct InstantVisitor<II: Instant, PP: Precision>(PhantomData<(II, PP)>);
 
-            impl<II: Instant> Visitor<'_> for InstantVisitor<II> {
-                type Value = SerdeInstant<II>;
+            impl<II: Instant, PP: Precision> InstantVisitor<II, PP> {
+                // Reconstruct the `Instant` from an absolute `SystemTime`, using the same
+                // `sys_now`/`II::now()` offset trick the serialize path uses.
+                fn instant_from(system_time: SystemTime) -> SerdeInstant<II, PP> {
+                    let sys_now = SystemTime::now();
+                    let now = II::now();
+                    let instant = if system_time > sys_now {
+                        now + (system_time.duration_since(sys_now).unwrap())
+                    } else {
+                        now - (sys_now.duration_since(system_time).unwrap())
+                    };
+                    SerdeInstant {
+                        inner: instant,
+                        precision: PhantomData,
+                    }
+                }
+            }
+
+            impl<II: Instant, PP: Precision> Visitor<'_> for InstantVisitor<II, PP> {
+                type Value = SerdeInstant<II, PP>;
 
                 fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                    write!(formatter, "an u64 generated with Datetime::timestamp_nanos_opt()",)
+                    write!(
+                        formatter,
+                        "an u64 count of epoch units generated with Datetime::timestamp_nanos_opt() or an RFC3339 string",
+                    )
                 }
 
                 fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
                 where E: de::Error {
-                    let datetime = DateTime::from_timestamp_nanos(value as i64);
+                    // Scale the stored unit count back up to nanoseconds.
+                    let nano = value.saturating_mul(PP::NANOS_PER_UNIT);
+                    let datetime = DateTime::from_timestamp_nanos(nano as i64);
 
                     let system_time: SystemTime = datetime.with_timezone(&Utc).into();
 
-                    // Calculate the `Instant` from the current time
-                    let sys_now = SystemTime::now();
-                    let now = II::now();
-                    let instant = if system_time > sys_now {
-                        now + (system_time.duration_since(sys_now).unwrap())
-                    } else {
-                        now - (sys_now.duration_since(system_time).unwrap())
-                    };
-                    Ok(SerdeInstant { inner: instant })
+                    Ok(Self::instant_from(system_time))
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where E: de::Error {
+                    let datetime = DateTime::parse_from_rfc3339(value).map_err(de::Error::custom)?;
+
+                    let system_time: SystemTime = datetime.with_timezone(&Utc).into();
+
+                    Ok(Self::instant_from(system_time))
                 }
             }
 
-            deserializer.deserialize_u64(InstantVisitor::<I>(Default::default()))
+            // Human-readable formats emit an RFC3339 string (see `Serialize`) but may still carry a
+            // legacy integer written before this change, so defer to the self-describing format via
+            // `deserialize_any` and let the visitor accept either. Compact/binary formats are not
+            // self-describing, so ask for the integer directly.
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(InstantVisitor::<I, P>(Default::default()))
+            } else {
+                deserializer.deserialize_u64(InstantVisitor::<I, P>(Default::default()))
+            }
         }
     }
 
@@ -170,17 +268,19 @@ mod serde_impl {
                 assert!((*deserialized - now) < Duration::from_millis(5));
             }
 
-            // Test serialization format
+            // Test serialization format: human-readable JSON emits an RFC3339 string.
 
-            let nano = "1721829051211301916";
-            let deserialized: SerdeInstantOf<UTConfig> = serde_json::from_str(nano).unwrap();
+            let rfc3339 = "\"2024-07-24T14:30:51.211301916Z\"";
+            let deserialized: SerdeInstantOf<UTConfig> = serde_json::from_str(rfc3339).unwrap();
             let serialized = serde_json::to_string(&deserialized).unwrap();
 
+            // Reconstruction through `I::now()` is inaccurate below the millisecond, so compare only
+            // the prefix down to milliseconds (dropping the 6 sub-millisecond digits and the `Z"`).
             assert_eq!(
-                nano[0..nano.len() - 6],
-                serialized[0..serialized.len() - 6],
+                rfc3339[0..rfc3339.len() - 8],
+                serialized[0..serialized.len() - 8],
                 "compare up to milli seconds: {}",
-                &nano[0..nano.len() - 6]
+                &rfc3339[0..rfc3339.len() - 8]
             );
         }
     }
@@ -188,11 +288,13 @@ mod serde_impl {
 
 #[cfg(feature = "rkyv")]
 mod rkyv_impl {
+    use std::marker::PhantomData;
     use std::time::SystemTime;
 
     use chrono::DateTime;
     use chrono::Utc;
 
+    use super::Precision;
     use super::SerdeInstant;
     use crate::Instant;
 
@@ -216,8 +318,10 @@ mod rkyv_impl {
         }
     }
 
-    impl<I> rkyv::Archive for SerdeInstant<I>
-    where I: Instant
+    impl<I, P> rkyv::Archive for SerdeInstant<I, P>
+    where
+        I: Instant,
+        P: Precision,
     {
         type Archived = ArchivedSerdeInstant;
         type Resolver = u64;
@@ -229,9 +333,10 @@ mod rkyv_impl {
         }
     }
 
-    impl<I, S> rkyv::Serialize<S> for SerdeInstant<I>
+    impl<I, P, S> rkyv::Serialize<S> for SerdeInstant<I, P>
     where
         I: Instant,
+        P: Precision,
         S: rkyv::rancor::Fallible + ?Sized,
     {
         fn serialize(&self, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
@@ -251,17 +356,21 @@ mod rkyv_impl {
 
             let datetime: DateTime<Utc> = system_time.into();
             let nano = datetime.timestamp_nanos_opt().expect("time out of range");
-            Ok(nano as u64)
+            // Store the count of `P`-sized units; `Nanos` keeps the original nanosecond integer.
+            Ok(nano as u64 / P::NANOS_PER_UNIT)
         }
     }
 
-    impl<I, D> rkyv::Deserialize<SerdeInstant<I>, D> for ArchivedSerdeInstant
+    impl<I, P, D> rkyv::Deserialize<SerdeInstant<I, P>, D> for ArchivedSerdeInstant
     where
         I: Instant,
+        P: Precision,
         D: rkyv::rancor::Fallible + ?Sized,
     {
-        fn deserialize(&self, _deserializer: &mut D) -> Result<SerdeInstant<I>, D::Error> {
-            let datetime = DateTime::from_timestamp_nanos(self.0.to_native() as i64);
+        fn deserialize(&self, _deserializer: &mut D) -> Result<SerdeInstant<I, P>, D::Error> {
+            // Scale the stored unit count back up to nanoseconds.
+            let nano = self.0.to_native().saturating_mul(P::NANOS_PER_UNIT);
+            let datetime = DateTime::from_timestamp_nanos(nano as i64);
             let system_time: SystemTime = datetime.with_timezone(&Utc).into();
 
             let sys_now = SystemTime::now();
@@ -272,7 +381,10 @@ mod rkyv_impl {
                 now - sys_now.duration_since(system_time).unwrap()
             };
 
-            Ok(SerdeInstant { inner: instant })
+            Ok(SerdeInstant {
+                inner: instant,
+                precision: PhantomData,
+            })
         }
     }
 
@@ -280,6 +392,7 @@ mod rkyv_impl {
     mod tests {
         use std::time::Duration;
 
+        use super::super::Seconds;
         use super::SerdeInstant;
         use crate::engine::testing::UTConfig;
         use crate::type_config::TypeConfigExt;
@@ -302,5 +415,23 @@ mod rkyv_impl {
                 assert!((*deserialized - now) < Duration::from_millis(5));
             }
         }
+
+        #[test]
+        fn test_rkyv_instant_seconds_precision() {
+            let now = UTConfig::<()>::now();
+            let serde_instant = SerdeInstant::<_, Seconds>::new(now);
+
+            let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&serde_instant).unwrap();
+
+            let deserialized = rkyv::from_bytes::<SerdeInstant<_, Seconds>, rkyv::rancor::Error>(&bytes).unwrap();
+
+            // Second precision drops everything below one second, so the reconstruction error is
+            // bounded by ~1s rather than the nanosecond default's ~5ms.
+            if now > *deserialized {
+                assert!((now - *deserialized) < Duration::from_secs(1));
+            } else {
+                assert!((*deserialized - now) < Duration::from_secs(1));
+            }
+        }
     }
 }